@@ -0,0 +1,108 @@
+use crate::math::clustering::clusterable::Clusterable;
+
+/// Cluster represents a group of points assigned to the same centroid.
+///
+/// # Type Parameters
+/// * `T` - The clusterable feature representation.
+#[derive(Debug, Clone)]
+pub struct Cluster<T: Clusterable> {
+    indices: Vec<usize>,
+    points: Vec<T>,
+}
+
+impl<T: Clusterable> Cluster<T> {
+    /// Creates a new, empty `Cluster`.
+    ///
+    /// # Returns
+    /// A new `Cluster` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            indices: Vec::new(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Adds a point to the cluster.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the point in the original input slice.
+    /// * `point` - The point to add.
+    pub fn add_point(&mut self, index: usize, point: &T) {
+        self.indices.push(index);
+        self.points.push(point.clone());
+    }
+
+    /// Removes every point from the cluster.
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.points.clear();
+    }
+
+    /// Returns the number of points in the cluster.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns whether the cluster contains no points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the indices, in the original input slice, of the points in the cluster.
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Computes the centroid of the points currently in the cluster.
+    ///
+    /// # Panics
+    /// Panics if the cluster is empty.
+    #[must_use]
+    pub fn centroid(&self) -> T {
+        T::centroid(self.points.iter()).expect("cluster must contain at least one point")
+    }
+}
+
+impl<T: Clusterable> Default for Cluster<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::point::Point;
+
+    #[test]
+    fn test_add_point_and_centroid() {
+        // Arrange
+        let mut cluster: Cluster<Point<2>> = Cluster::new();
+
+        // Act
+        cluster.add_point(0, &Point([0.0, 0.0]));
+        cluster.add_point(1, &Point([2.0, 0.0]));
+
+        // Assert
+        assert_eq!(cluster.len(), 2);
+        assert_eq!(cluster.indices(), &[0, 1]);
+        assert_eq!(cluster.centroid(), Point([1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_clear() {
+        // Arrange
+        let mut cluster: Cluster<Point<2>> = Cluster::new();
+        cluster.add_point(0, &Point([1.0, 1.0]));
+
+        // Act
+        cluster.clear();
+
+        // Assert
+        assert!(cluster.is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::math::metrics::DistanceMetric;
+use crate::math::point::Point;
+
+/// Clusterable abstracts the feature representation used by the clustering backends.
+///
+/// Routing all distance and centroid math through this trait lets callers fold extra dimensions
+/// into the clustering objective - for example weighting pixels by frequency, or appending
+/// normalized x/y coordinates to bias toward spatial coherence - without the crate having to
+/// enumerate every possible feature layout.
+pub trait Clusterable: Clone {
+    /// Computes the distance between `self` and `other` under the given metric.
+    fn distance(&self, other: &Self, metric: DistanceMetric) -> f32;
+
+    /// Computes the centroid of the given items.
+    ///
+    /// # Returns
+    /// The centroid, or `None` if `items` is empty.
+    fn centroid<'a>(items: impl Iterator<Item = &'a Self>) -> Option<Self>
+    where
+        Self: 'a;
+
+    /// Computes the weighted centroid of the given `(item, weight)` pairs, as used by
+    /// kernel-density-weighted backends such as [`MeanShift`](super::mean_shift::MeanShift).
+    ///
+    /// # Returns
+    /// The weighted centroid, or `None` if `items` is empty or every weight is zero.
+    fn weighted_centroid<'a>(items: impl Iterator<Item = (&'a Self, f32)>) -> Option<Self>
+    where
+        Self: 'a;
+}
+
+impl<const N: usize> Clusterable for Point<N> {
+    #[inline]
+    fn distance(&self, other: &Self, metric: DistanceMetric) -> f32 {
+        metric.measure(self, other)
+    }
+
+    fn centroid<'a>(items: impl Iterator<Item = &'a Self>) -> Option<Self>
+    where
+        Self: 'a,
+    {
+        let mut sum = [0.0_f32; N];
+        let mut count = 0usize;
+        for point in items {
+            for (dim, value) in sum.iter_mut().enumerate() {
+                *value += point.0[dim];
+            }
+            count += 1;
+        }
+
+        if count == 0 {
+            return None;
+        }
+        for value in &mut sum {
+            *value /= count as f32;
+        }
+        Some(Point(sum))
+    }
+
+    fn weighted_centroid<'a>(items: impl Iterator<Item = (&'a Self, f32)>) -> Option<Self>
+    where
+        Self: 'a,
+    {
+        let mut sum = [0.0_f32; N];
+        let mut weight_total = 0.0_f32;
+        for (point, weight) in items {
+            if weight <= 0.0 {
+                continue;
+            }
+            for (dim, value) in sum.iter_mut().enumerate() {
+                *value += weight * point.0[dim];
+            }
+            weight_total += weight;
+        }
+
+        if weight_total <= 0.0 {
+            return None;
+        }
+        for value in &mut sum {
+            *value /= weight_total;
+        }
+        Some(Point(sum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        // Arrange
+        let a = Point([0.0, 0.0]);
+        let b = Point([3.0, 4.0]);
+
+        // Act
+        let distance = a.distance(&b, DistanceMetric::Euclidean);
+
+        // Assert
+        assert!((distance - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_centroid() {
+        // Arrange
+        let points = [Point([0.0, 0.0]), Point([2.0, 0.0]), Point([4.0, 0.0])];
+
+        // Act
+        let centroid = Point::centroid(points.iter()).unwrap();
+
+        // Assert
+        assert_eq!(centroid, Point([2.0, 0.0]));
+    }
+
+    #[test]
+    fn test_centroid_empty() {
+        // Arrange
+        let points: Vec<Point<2>> = Vec::new();
+
+        // Act
+        let centroid = Point::centroid(points.iter());
+
+        // Assert
+        assert_eq!(centroid, None);
+    }
+
+    #[test]
+    fn test_weighted_centroid() {
+        // Arrange
+        let points = [Point([0.0, 0.0]), Point([4.0, 0.0])];
+        let weights = [3.0, 1.0];
+
+        // Act
+        let centroid = Point::weighted_centroid(points.iter().zip(weights)).unwrap();
+
+        // Assert
+        assert_eq!(centroid, Point([1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_weighted_centroid_all_zero_weight() {
+        // Arrange
+        let points = [Point([0.0, 0.0]), Point([4.0, 0.0])];
+        let weights = [0.0, 0.0];
+
+        // Act
+        let centroid = Point::weighted_centroid(points.iter().zip(weights));
+
+        // Assert
+        assert_eq!(centroid, None);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::math::clustering::cluster::Cluster;
+use crate::math::clustering::clusterable::Clusterable;
 use crate::math::metrics::DistanceMetric;
-use crate::math::point::Point;
+use crate::math::vp_tree::VpTree;
 use rand::Rng;
 use rand_distr::{Distribution, WeightedAliasIndex};
 
@@ -61,14 +62,14 @@ impl<R: Rng + Clone> Kmeans<R> {
     /// Fits the K-means algorithm to the given points.
     ///
     /// # Type Parameters
-    /// * `N` - The number of dimensions.
+    /// * `T` - The clusterable feature representation.
     ///
     /// # Arguments
     /// * `points` - The points to cluster.
     ///
     /// # Returns
     /// The clusters of the points.
-    pub fn fit<const N: usize>(&self, points: &[Point<N>]) -> Vec<Cluster<N>> {
+    pub fn fit<T: Clusterable>(&self, points: &[T]) -> Vec<Cluster<T>> {
         if points.is_empty() {
             return Vec::new();
         }
@@ -89,46 +90,44 @@ impl<R: Rng + Clone> Kmeans<R> {
         let index = rng.gen_range(0..points.len());
 
         // Initialize the centroids.
-        let mut centroids = Vec::with_capacity(self.k);
-        centroids.push(points[index]);
+        let mut centroids: Vec<T> = Vec::with_capacity(self.k);
+        centroids.push(points[index].clone());
 
         for _ in 1..self.k {
             let mut distances = vec![f32::INFINITY; points.len()];
             for (i, point) in points.iter().enumerate() {
                 for centroid in &centroids {
-                    let distance = self.metric.measure(point, centroid);
+                    let distance = point.distance(centroid, self.metric);
                     distances[i] = distances[i].min(distance);
                 }
             }
 
             let weighted_index = WeightedAliasIndex::new(distances).unwrap();
             let index = weighted_index.sample(&mut rng);
-            centroids.push(points[index]);
+            centroids.push(points[index].clone());
         }
 
-        let mut clusters = vec![Cluster::new(); self.k];
+        let mut clusters: Vec<Cluster<T>> = vec![Cluster::new(); self.k];
         for _ in 0..self.max_iter {
             for cluster in &mut clusters {
                 cluster.clear();
             }
 
+            // Rebuild the (small) vantage-point tree over the current centroids so
+            // each point can find its nearest centroid in sub-linear time instead of
+            // scanning all `k` centroids.
+            let tree = VpTree::build(&centroids, self.metric);
             for (index, point) in points.iter().enumerate() {
-                let mut min_distance = f32::INFINITY;
-                let mut cluster_id = 0;
-                for (i, centroid) in centroids.iter().enumerate() {
-                    let distance = self.metric.measure(point, centroid);
-                    if distance < min_distance {
-                        min_distance = distance;
-                        cluster_id = i;
-                    }
-                }
+                let (cluster_id, _) = tree
+                    .nearest(point)
+                    .expect("centroids must not be empty while fitting");
                 clusters[cluster_id].add_point(index, point);
             }
 
             let mut max_shift = 0.0_f32;
-            let new_centroids = clusters.iter().map(|cluster| *cluster.centroid()).collect();
+            let new_centroids: Vec<T> = clusters.iter().map(Cluster::centroid).collect();
             for (old, new) in centroids.iter().zip(&new_centroids) {
-                let distance = self.metric.measure(old, new);
+                let distance = old.distance(new, self.metric);
                 max_shift = max_shift.max(distance);
             }
 
@@ -148,8 +147,7 @@ mod tests {
     #[test]
     fn test_new_kmeans() {
         // Act
-        let kmeans =
-            Kmeans::new(3, 10, 1e-3, rand::thread_rng(), DistanceMetric::Euclidean).unwrap();
+        let kmeans = Kmeans::new(3, 10, 1e-3, rand::thread_rng(), DistanceMetric::Euclidean).unwrap();
 
         // Assert
         assert_eq!(kmeans.k, 3);
@@ -157,4 +155,23 @@ mod tests {
         assert_eq!(kmeans.tolerance, 1e-3);
         assert_eq!(kmeans.metric, DistanceMetric::Euclidean);
     }
+
+    #[test]
+    fn test_fit_generic_over_clusterable() {
+        // Arrange
+        use crate::math::point::Point;
+        let kmeans = Kmeans::new(2, 100, 1e-3, rand::thread_rng(), DistanceMetric::Euclidean).unwrap();
+        let points = vec![
+            Point([0.0, 0.0]),
+            Point([0.2, -0.1]),
+            Point([10.0, 10.0]),
+            Point([10.1, 9.9]),
+        ];
+
+        // Act
+        let clusters = kmeans.fit(&points);
+
+        // Assert
+        assert_eq!(clusters.len(), 2);
+    }
 }
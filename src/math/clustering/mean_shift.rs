@@ -0,0 +1,203 @@
+use crate::math::clustering::cluster::Cluster;
+use crate::math::clustering::clusterable::Clusterable;
+use crate::math::metrics::DistanceMetric;
+
+/// Kernel defines the weighting profile used by [`MeanShift`] when averaging
+/// neighboring points during a mean-shift iteration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kernel {
+    /// Gaussian kernel: `exp(-0.5 * u^2)`.
+    Gaussian,
+    /// Epanechnikov kernel: `max(0, 1 - u^2)`.
+    Epanechnikov,
+}
+
+impl Kernel {
+    /// Computes the kernel weight for a normalized distance `u = d / h`.
+    #[inline]
+    fn weight(&self, u: f32) -> f32 {
+        match self {
+            Kernel::Gaussian => (-0.5 * u * u).exp(),
+            Kernel::Epanechnikov => (1.0 - u * u).max(0.0),
+        }
+    }
+}
+
+/// MeanShift represents the mean-shift clustering algorithm.
+///
+/// Unlike [`Kmeans`](super::kmeans::Kmeans), it does not take the number of clusters as input;
+/// instead it climbs the kernel density estimate of the points toward their local modes, and the
+/// number of clusters emerges from how many distinct modes the points converge to.
+#[derive(Debug)]
+pub struct MeanShift {
+    bandwidth: f32,
+    max_iter: usize,
+    tolerance: f32,
+    kernel: Kernel,
+    metric: DistanceMetric,
+}
+
+impl MeanShift {
+    /// Creates a new `MeanShift` instance.
+    ///
+    /// # Arguments
+    /// * `bandwidth` - The bandwidth `h` of the kernel density estimate.
+    /// * `max_iter` - The maximum number of shift iterations per seed.
+    /// * `tolerance` - The shift magnitude below which a seed is considered converged.
+    /// * `kernel` - The kernel profile used to weight neighboring points.
+    /// * `metric` - The distance metric to use.
+    ///
+    /// # Returns
+    /// A new `MeanShift` instance.
+    ///
+    /// # Errors
+    /// Returns an error if the bandwidth is less than or equal to zero, the maximum number of
+    /// iterations is zero, or the tolerance is less than or equal to zero.
+    pub fn new(
+        bandwidth: f32,
+        max_iter: usize,
+        tolerance: f32,
+        kernel: Kernel,
+        metric: DistanceMetric,
+    ) -> Result<Self, &'static str> {
+        if bandwidth <= 0.0 {
+            return Err("The bandwidth must be greater than zero.");
+        }
+        if max_iter == 0 {
+            return Err("The maximum number of iterations must be greater than zero.");
+        }
+        if tolerance <= 0.0 {
+            return Err("The tolerance must be greater than zero.");
+        }
+        Ok(Self {
+            bandwidth,
+            max_iter,
+            tolerance,
+            kernel,
+            metric,
+        })
+    }
+
+    /// Fits the mean-shift algorithm to the given points.
+    ///
+    /// # Type Parameters
+    /// * `T` - The clusterable feature representation.
+    ///
+    /// # Arguments
+    /// * `points` - The points to cluster.
+    ///
+    /// # Returns
+    /// The clusters discovered in the points. The number of clusters is not fixed up front; it
+    /// is however many distinct modes the points converge to.
+    pub fn fit<T: Clusterable>(&self, points: &[T]) -> Vec<Cluster<T>> {
+        if points.is_empty() {
+            return Vec::new();
+        }
+
+        let modes: Vec<T> = points.iter().map(|seed| self.shift_to_mode(seed.clone(), points)).collect();
+
+        // Points whose trajectories converged to the same mode (within the bandwidth) are merged
+        // into a single cluster. The first mode of each cluster is kept only to decide merges; the
+        // cluster's centroid is the mean of its member points, not the converged mode.
+        let mut mode_representatives: Vec<T> = Vec::new();
+        let mut clusters: Vec<Cluster<T>> = Vec::new();
+        for (index, (point, mode)) in points.iter().zip(modes.iter()).enumerate() {
+            let existing = mode_representatives
+                .iter()
+                .position(|representative| representative.distance(mode, self.metric) <= self.bandwidth);
+            match existing {
+                Some(cluster_id) => clusters[cluster_id].add_point(index, point),
+                None => {
+                    mode_representatives.push(mode.clone());
+                    let mut cluster = Cluster::new();
+                    cluster.add_point(index, point);
+                    clusters.push(cluster);
+                }
+            }
+        }
+        clusters
+    }
+
+    /// Iteratively shifts a seed point toward the nearest mode of the kernel density estimate,
+    /// using [`Clusterable::weighted_centroid`] to average neighbors weighted by the kernel.
+    fn shift_to_mode<T: Clusterable>(&self, seed: T, points: &[T]) -> T {
+        let mut current = seed;
+        for _ in 0..self.max_iter {
+            let weights = points
+                .iter()
+                .map(|point| self.kernel.weight(current.distance(point, self.metric) / self.bandwidth));
+
+            let Some(shifted) = T::weighted_centroid(points.iter().zip(weights)) else {
+                break;
+            };
+
+            let shift = current.distance(&shifted, self.metric);
+            current = shifted;
+            if shift < self.tolerance {
+                break;
+            }
+        }
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::point::Point;
+
+    #[test]
+    fn test_new_mean_shift() {
+        // Act
+        let mean_shift = MeanShift::new(1.0, 100, 1e-3, Kernel::Gaussian, DistanceMetric::Euclidean).unwrap();
+
+        // Assert
+        assert_eq!(mean_shift.bandwidth, 1.0);
+        assert_eq!(mean_shift.max_iter, 100);
+        assert_eq!(mean_shift.tolerance, 1e-3);
+        assert_eq!(mean_shift.kernel, Kernel::Gaussian);
+        assert_eq!(mean_shift.metric, DistanceMetric::Euclidean);
+    }
+
+    #[test]
+    fn test_new_mean_shift_invalid_bandwidth() {
+        // Act
+        let result = MeanShift::new(0.0, 100, 1e-3, Kernel::Gaussian, DistanceMetric::Euclidean);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_fit_finds_two_dense_groups() {
+        // Arrange
+        let mean_shift = MeanShift::new(2.0, 100, 1e-3, Kernel::Gaussian, DistanceMetric::Euclidean).unwrap();
+        let points = vec![
+            Point([0.0, 0.0]),
+            Point([0.2, -0.1]),
+            Point([-0.1, 0.2]),
+            Point([20.0, 20.0]),
+            Point([20.2, 19.8]),
+            Point([19.9, 20.1]),
+        ];
+
+        // Act
+        let clusters = mean_shift.fit(&points);
+
+        // Assert
+        assert_eq!(clusters.len(), 2);
+    }
+
+    #[test]
+    fn test_fit_empty() {
+        // Arrange
+        let mean_shift = MeanShift::new(1.0, 100, 1e-3, Kernel::Epanechnikov, DistanceMetric::Euclidean).unwrap();
+        let points: Vec<Point<2>> = Vec::new();
+
+        // Act
+        let clusters = mean_shift.fit(&points);
+
+        // Assert
+        assert!(clusters.is_empty());
+    }
+}
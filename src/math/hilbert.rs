@@ -0,0 +1,151 @@
+/// Computes the distance of a quantized 3D point along a Hilbert space-filling curve.
+///
+/// This is Skilling's transpose algorithm: the coordinates are first converted to their Hilbert
+/// "transpose" representation by walking the bit levels from the high bit down and, at each
+/// level, conditionally inverting and exchanging the lower coordinates (the inverse-Gray-code
+/// step), and are then Gray-encoded. The resulting `n` transposed words are finally interleaved,
+/// high bit first, into a single curve distance.
+///
+/// # Arguments
+/// * `coords` - The quantized `(x, y, z)` coordinates, each in `0..2^bits`.
+/// * `bits` - The number of bits used to quantize each coordinate.
+///
+/// # Returns
+/// The distance of `coords` along the Hilbert curve, fitting in a `u64` for `bits <= 21`.
+#[must_use]
+pub fn hilbert_index(coords: [u32; 3], bits: u32) -> u64 {
+    const N: usize = 3;
+    let mut x = coords;
+
+    if bits > 0 {
+        let m = 1u32 << (bits - 1);
+
+        // Inverse-undo: rotate and reflect the lower coordinates at each bit level.
+        let mut q = m;
+        while q > 1 {
+            let p = q - 1;
+            for i in 0..N {
+                if x[i] & q != 0 {
+                    x[0] ^= p;
+                } else {
+                    let t = (x[0] ^ x[i]) & p;
+                    x[0] ^= t;
+                    x[i] ^= t;
+                }
+            }
+            q >>= 1;
+        }
+
+        // Gray-encode the transposed coordinates.
+        for i in 1..N {
+            x[i] ^= x[i - 1];
+        }
+        let mut t = 0u32;
+        let mut q = m;
+        while q > 1 {
+            if x[N - 1] & q != 0 {
+                t ^= q - 1;
+            }
+            q >>= 1;
+        }
+        for value in &mut x {
+            *value ^= t;
+        }
+    }
+
+    // Interleave the transposed bits, high bit first, into a single distance.
+    let mut index: u64 = 0;
+    for bit in (0..bits).rev() {
+        for value in &x {
+            index = (index << 1) | u64::from((value >> bit) & 1);
+        }
+    }
+    index
+}
+
+/// Sorts `items` in place by ascending position along a 3D Hilbert curve.
+///
+/// Sorting by Hilbert distance rather than by a single channel (lightness, hue, ...) keeps
+/// adjacent items close to each other in the full color space, not just along one axis.
+///
+/// # Arguments
+/// * `items` - The items to sort.
+/// * `bits` - The bit depth `b` used to quantize each coordinate; higher values trade more
+///   precision for a larger curve distance.
+/// * `quantize` - Maps an item to its quantized `(x, y, z)` coordinates in the chosen color space.
+pub fn sort_by_hilbert_curve<T>(items: &mut [T], bits: u32, quantize: impl Fn(&T) -> [u32; 3]) {
+    items.sort_by_key(|item| hilbert_index(quantize(item), bits));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hilbert_index_known_cube_corners() {
+        // Arrange: with a single bit per axis, the Hilbert curve visits the 8 corners of the
+        // unit cube in this exact order (Skilling's algorithm reduces to the reflected binary
+        // Gray code when `bits == 1`).
+        let expected = [
+            ([0, 0, 0], 0),
+            ([0, 0, 1], 1),
+            ([0, 1, 1], 2),
+            ([0, 1, 0], 3),
+            ([1, 1, 0], 4),
+            ([1, 1, 1], 5),
+            ([1, 0, 1], 6),
+            ([1, 0, 0], 7),
+        ];
+
+        for (coords, index) in expected {
+            // Act
+            let actual = hilbert_index(coords, 1);
+
+            // Assert
+            assert_eq!(actual, index, "coords {coords:?} should map to index {index}");
+        }
+    }
+
+    #[test]
+    fn test_hilbert_index_is_a_bijection() {
+        // Arrange
+        let bits = 3;
+        let side = 1u32 << bits;
+        let mut indices = Vec::new();
+
+        // Act
+        for x in 0..side {
+            for y in 0..side {
+                for z in 0..side {
+                    indices.push(hilbert_index([x, y, z], bits));
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+
+        // Assert: every one of the `side^3` quantized points maps to a distinct index.
+        assert_eq!(indices.len(), (side * side * side) as usize);
+    }
+
+    #[test]
+    fn test_hilbert_index_origin_is_zero() {
+        // Act
+        let index = hilbert_index([0, 0, 0], 4);
+
+        // Assert
+        assert_eq!(index, 0);
+    }
+
+    #[test]
+    fn test_sort_by_hilbert_curve() {
+        // Arrange
+        let mut points = [[1, 0, 0], [0, 0, 0], [1, 1, 0], [0, 0, 1]];
+
+        // Act
+        sort_by_hilbert_curve(&mut points, 1, |point| *point);
+
+        // Assert
+        assert_eq!(points, [[0, 0, 0], [0, 0, 1], [1, 1, 0], [1, 0, 0]]);
+    }
+}
@@ -0,0 +1,273 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::math::clustering::clusterable::Clusterable;
+use crate::math::metrics::DistanceMetric;
+
+/// A node of a [`VpTree`], storing a vantage point and the radius `threshold`
+/// that separates its "inside" subtree (points within `threshold`) from its
+/// "outside" subtree (points at or beyond it).
+#[derive(Debug)]
+struct Node<T: Clusterable> {
+    index: usize,
+    point: T,
+    threshold: f32,
+    inside: Option<Box<Node<T>>>,
+    outside: Option<Box<Node<T>>>,
+}
+
+/// VpTree is a vantage-point tree that indexes a set of clusterable points for
+/// efficient nearest-neighbor search under a `DistanceMetric`.
+///
+/// # Type Parameters
+/// * `T` - The clusterable feature representation being indexed.
+#[derive(Debug)]
+pub struct VpTree<T: Clusterable> {
+    root: Option<Box<Node<T>>>,
+    metric: DistanceMetric,
+}
+
+impl<T: Clusterable> VpTree<T> {
+    /// Builds a vantage-point tree over the given points.
+    ///
+    /// # Arguments
+    /// * `points` - The points to index.
+    /// * `metric` - The distance metric used to compare points.
+    ///
+    /// # Returns
+    /// A new `VpTree` instance. The index reported by `nearest` and
+    /// `k_nearest` refers to the position of the point within `points`.
+    #[must_use]
+    pub fn build(points: &[T], metric: DistanceMetric) -> Self {
+        let mut items: Vec<(usize, T)> = points.iter().cloned().enumerate().collect();
+        let root = Self::build_node(&mut items, metric);
+        Self { root, metric }
+    }
+
+    fn build_node(items: &mut [(usize, T)], metric: DistanceMetric) -> Option<Box<Node<T>>> {
+        if items.is_empty() {
+            return None;
+        }
+
+        let (vantage_index, vantage_point) = items[0].clone();
+        let rest = &mut items[1..];
+        if rest.is_empty() {
+            return Some(Box::new(Node {
+                index: vantage_index,
+                point: vantage_point,
+                threshold: 0.0,
+                inside: None,
+                outside: None,
+            }));
+        }
+
+        // Partition the remaining points around their median distance to the
+        // vantage point: `rest[..mid]` becomes the "inside" subtree and the
+        // rest (including the median itself) becomes "outside".
+        let mid = rest.len() / 2;
+        rest.select_nth_unstable_by(mid, |(_, a), (_, b)| {
+            let da = vantage_point.distance(a, metric);
+            let db = vantage_point.distance(b, metric);
+            da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+        });
+        let threshold = vantage_point.distance(&rest[mid].1, metric);
+        let (inside_items, outside_items) = rest.split_at_mut(mid);
+
+        Some(Box::new(Node {
+            index: vantage_index,
+            point: vantage_point,
+            threshold,
+            inside: Self::build_node(inside_items, metric),
+            outside: Self::build_node(outside_items, metric),
+        }))
+    }
+
+    /// Finds the nearest indexed point to the given query.
+    ///
+    /// # Arguments
+    /// * `query` - The query point.
+    ///
+    /// # Returns
+    /// The index and distance of the nearest point, or `None` if the tree holds no points.
+    #[must_use]
+    pub fn nearest(&self, query: &T) -> Option<(usize, f32)> {
+        let mut best: Option<(usize, f32)> = None;
+        Self::search_nearest(&self.root, query, self.metric, &mut best);
+        best
+    }
+
+    fn search_nearest(
+        node: &Option<Box<Node<T>>>,
+        query: &T,
+        metric: DistanceMetric,
+        best: &mut Option<(usize, f32)>,
+    ) {
+        let Some(node) = node else {
+            return;
+        };
+
+        let distance = query.distance(&node.point, metric);
+        if best.is_none_or(|(_, tau)| distance < tau) {
+            *best = Some((node.index, distance));
+        }
+
+        let (near, far) = if distance < node.threshold {
+            (&node.inside, &node.outside)
+        } else {
+            (&node.outside, &node.inside)
+        };
+        Self::search_nearest(near, query, metric, best);
+
+        let tau = best.map_or(f32::INFINITY, |(_, tau)| tau);
+        if (distance - node.threshold).abs() <= tau {
+            Self::search_nearest(far, query, metric, best);
+        }
+    }
+
+    /// Finds the `k` nearest indexed points to the given query, sorted by ascending distance.
+    ///
+    /// # Arguments
+    /// * `query` - The query point.
+    /// * `k` - The number of neighbors to find.
+    ///
+    /// # Returns
+    /// The indices and distances of up to `k` nearest points.
+    #[must_use]
+    pub fn k_nearest(&self, query: &T, k: usize) -> Vec<(usize, f32)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut heap: BinaryHeap<Candidate> = BinaryHeap::with_capacity(k + 1);
+        Self::search_k_nearest(&self.root, query, self.metric, k, &mut heap);
+
+        let mut results: Vec<(usize, f32)> = heap
+            .into_iter()
+            .map(|candidate| (candidate.index, candidate.distance))
+            .collect();
+        results.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        results
+    }
+
+    fn search_k_nearest(
+        node: &Option<Box<Node<T>>>,
+        query: &T,
+        metric: DistanceMetric,
+        k: usize,
+        heap: &mut BinaryHeap<Candidate>,
+    ) {
+        let Some(node) = node else {
+            return;
+        };
+
+        let distance = query.distance(&node.point, metric);
+        if heap.len() < k {
+            heap.push(Candidate {
+                index: node.index,
+                distance,
+            });
+        } else if heap.peek().is_some_and(|farthest| distance < farthest.distance) {
+            heap.pop();
+            heap.push(Candidate {
+                index: node.index,
+                distance,
+            });
+        }
+
+        let (near, far) = if distance < node.threshold {
+            (&node.inside, &node.outside)
+        } else {
+            (&node.outside, &node.inside)
+        };
+        Self::search_k_nearest(near, query, metric, k, heap);
+
+        let tau = if heap.len() < k {
+            f32::INFINITY
+        } else {
+            heap.peek().map_or(f32::INFINITY, |candidate| candidate.distance)
+        };
+        if (distance - node.threshold).abs() <= tau {
+            Self::search_k_nearest(far, query, metric, k, heap);
+        }
+    }
+}
+
+/// A candidate neighbor tracked by the bounded max-heap used during k-nearest search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    index: usize,
+    distance: f32,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.partial_cmp(&other.distance).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::point::Point;
+
+    fn sample_points() -> Vec<Point<2>> {
+        vec![
+            Point([0.0, 0.0]),
+            Point([1.0, 0.0]),
+            Point([0.0, 1.0]),
+            Point([5.0, 5.0]),
+            Point([5.0, 6.0]),
+            Point([-3.0, -2.0]),
+        ]
+    }
+
+    #[test]
+    fn test_nearest() {
+        // Arrange
+        let points = sample_points();
+        let tree = VpTree::build(&points, DistanceMetric::Euclidean);
+
+        // Act
+        let (index, distance) = tree.nearest(&Point([5.0, 5.2])).unwrap();
+
+        // Assert
+        assert_eq!(index, 3);
+        assert!(distance < 1.0);
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        // Arrange
+        let points = sample_points();
+        let tree = VpTree::build(&points, DistanceMetric::Euclidean);
+
+        // Act
+        let neighbors = tree.k_nearest(&Point([0.0, 0.0]), 3);
+
+        // Assert
+        assert_eq!(neighbors.len(), 3);
+        assert_eq!(neighbors[0].0, 0);
+        for pair in neighbors.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+        }
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        // Arrange
+        let points: Vec<Point<2>> = Vec::new();
+        let tree = VpTree::build(&points, DistanceMetric::Euclidean);
+
+        // Act & Assert
+        assert_eq!(tree.nearest(&Point([0.0, 0.0])), None);
+        assert!(tree.k_nearest(&Point([0.0, 0.0]), 3).is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use rand::rngs::ThreadRng;
+use rand::{Error, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::math::clustering::cluster::Cluster;
+use crate::math::clustering::clusterable::Clusterable;
+use crate::math::clustering::kmeans::Kmeans;
+use crate::math::metrics::DistanceMetric;
+use crate::math::point::Point;
+use crate::quantize::{Dither, Quantizer};
+
+/// PaletteRng is the random number generator used by [`PaletteBuilder`]: either a `ChaCha8Rng`
+/// seeded explicitly for reproducible palettes, or the thread-local default when no seed was
+/// requested.
+///
+/// The seeded variant is boxed since a `ChaCha8Rng` (over 300 bytes of cipher state and buffered output) is much
+/// larger than a `ThreadRng` handle, and we do not want every `PaletteRng` sized for the bigger case.
+#[derive(Debug, Clone)]
+pub enum PaletteRng {
+    /// A `ChaCha8Rng` seeded from an explicit `u64`, for byte-identical repeated runs.
+    Seeded(Box<ChaCha8Rng>),
+    /// The thread-local default generator, used when reproducibility was not requested.
+    Default(ThreadRng),
+}
+
+impl RngCore for PaletteRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            PaletteRng::Seeded(rng) => rng.next_u32(),
+            PaletteRng::Default(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            PaletteRng::Seeded(rng) => rng.next_u64(),
+            PaletteRng::Default(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            PaletteRng::Seeded(rng) => rng.fill_bytes(dest),
+            PaletteRng::Default(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self {
+            PaletteRng::Seeded(rng) => rng.try_fill_bytes(dest),
+            PaletteRng::Default(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
+/// PaletteBuilder configures and runs the K-means clustering stage of palette extraction.
+#[derive(Debug, Clone)]
+pub struct PaletteBuilder {
+    k: usize,
+    max_iter: usize,
+    tolerance: f32,
+    metric: DistanceMetric,
+    seed: Option<u64>,
+}
+
+impl PaletteBuilder {
+    /// Creates a new `PaletteBuilder` with the given clustering parameters.
+    ///
+    /// # Arguments
+    /// * `k` - The number of swatches to extract.
+    /// * `max_iter` - The maximum number of K-means iterations.
+    /// * `tolerance` - The tolerance for K-means convergence.
+    ///
+    /// # Returns
+    /// A new `PaletteBuilder` instance, using the thread-local RNG until [`PaletteBuilder::with_seed`] is called.
+    #[must_use]
+    pub fn new(k: usize, max_iter: usize, tolerance: f32) -> Self {
+        Self {
+            k,
+            max_iter,
+            tolerance,
+            metric: DistanceMetric::Euclidean,
+            seed: None,
+        }
+    }
+
+    /// Fixes the random seed used by the K-means clustering stage, so that repeated extractions
+    /// over the same input produce byte-identical palettes.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed to initialize the PRNG with.
+    ///
+    /// # Returns
+    /// `self`, for chaining.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    fn rng(&self) -> PaletteRng {
+        match self.seed {
+            Some(seed) => PaletteRng::Seeded(Box::new(ChaCha8Rng::seed_from_u64(seed))),
+            None => PaletteRng::Default(rand::thread_rng()),
+        }
+    }
+
+    /// Extracts a palette from the given points.
+    ///
+    /// # Type Parameters
+    /// * `T` - The clusterable feature representation.
+    ///
+    /// # Arguments
+    /// * `points` - The points to cluster into swatches.
+    ///
+    /// # Returns
+    /// The extracted clusters.
+    ///
+    /// # Panics
+    /// Panics if this builder was configured with an invalid `k`, `max_iter`, or `tolerance`; see
+    /// [`Kmeans::new`].
+    pub fn build<T: Clusterable>(&self, points: &[T]) -> Vec<Cluster<T>> {
+        let kmeans = Kmeans::new(self.k, self.max_iter, self.tolerance, self.rng(), self.metric)
+            .expect("palette builder must be configured with valid clustering parameters");
+        kmeans.fit(points)
+    }
+
+    /// Extracts a palette from the given points and wraps its swatches in a [`Quantizer`], so the
+    /// palette this builder just produced can be used directly to remap a source image.
+    ///
+    /// # Arguments
+    /// * `points` - The points to cluster into swatches.
+    /// * `dither` - The dithering mode the resulting quantizer should use.
+    ///
+    /// # Returns
+    /// The extracted clusters, and a `Quantizer` built from their centroids.
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`PaletteBuilder::build`], and if `points` is empty.
+    pub fn build_quantizer<const N: usize>(
+        &self,
+        points: &[Point<N>],
+        dither: Dither,
+    ) -> (Vec<Cluster<Point<N>>>, Quantizer<N>) {
+        let clusters = self.build(points);
+        let swatches: Vec<Point<N>> = clusters.iter().map(Cluster::centroid).collect();
+        let quantizer = Quantizer::new(swatches, dither, self.metric)
+            .expect("a non-empty input always yields at least one swatch");
+        (clusters, quantizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_points() -> Vec<Point<2>> {
+        vec![
+            Point([0.0, 0.0]),
+            Point([0.1, -0.1]),
+            Point([10.0, 10.0]),
+            Point([10.2, 9.8]),
+            Point([-8.0, 5.0]),
+            Point([-7.8, 5.3]),
+        ]
+    }
+
+    fn centroids(builder: &PaletteBuilder, points: &[Point<2>]) -> Vec<Point<2>> {
+        builder.build(points).iter().map(Cluster::centroid).collect()
+    }
+
+    #[test]
+    fn test_with_seed_is_reproducible() {
+        // Arrange
+        let points = sample_points();
+        let builder = PaletteBuilder::new(3, 100, 1e-3).with_seed(42);
+
+        // Act
+        let first = centroids(&builder, &points);
+        let second = centroids(&builder, &points);
+
+        // Assert
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_different_seeds_can_diverge() {
+        // Arrange
+        let points = sample_points();
+        let baseline = centroids(&PaletteBuilder::new(3, 100, 1e-3).with_seed(1), &points);
+
+        // Act
+        let diverged = (2..50_u64).any(|seed| {
+            let other = centroids(&PaletteBuilder::new(3, 100, 1e-3).with_seed(seed), &points);
+            other != baseline
+        });
+
+        // Assert
+        assert!(diverged, "expected at least one of many seeds to diverge from seed 1");
+    }
+
+    #[test]
+    fn test_build_quantizer_uses_extracted_swatches() {
+        // Arrange
+        let points = sample_points();
+        let builder = PaletteBuilder::new(3, 100, 1e-3).with_seed(7);
+
+        // Act
+        let (clusters, quantizer) = builder.build_quantizer(&points, Dither::None);
+        let (indices, _) = quantizer.quantize(points.len(), &points, |point| crate::quantize::Rgba {
+            r: point.0[0] as u8,
+            g: point.0[1] as u8,
+            b: 0,
+            a: 255,
+        });
+
+        // Assert
+        assert_eq!(clusters.len(), 3);
+        assert_eq!(indices.len(), points.len());
+    }
+}
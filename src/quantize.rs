@@ -0,0 +1,216 @@
+use crate::math::metrics::DistanceMetric;
+use crate::math::point::Point;
+use crate::math::vp_tree::VpTree;
+
+/// Dither selects how residual quantization error is handled when mapping a source image onto a
+/// fixed set of palette swatches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dither {
+    /// No error diffusion; each pixel is mapped to its nearest swatch independently.
+    None,
+    /// Floyd-Steinberg error diffusion, distributing each pixel's quantization error to its
+    /// not-yet-processed neighbors so gradients do not band.
+    FloydSteinberg,
+}
+
+/// An 8-bit RGBA color, used for the reconstructed image returned by [`Quantizer::quantize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+/// Quantizer maps the pixels of a source image onto a fixed palette of swatches.
+///
+/// Pixels and swatches are expressed as points in whatever working color space the caller chose
+/// (for example quantized Lab); `Quantizer` only concerns itself with finding the nearest swatch
+/// and, optionally, diffusing the resulting quantization error. Converting a working-space swatch
+/// back into a displayable color is the caller's job, via the `to_rgba` function passed to
+/// [`Quantizer::quantize`], since that conversion depends on which color space `N` represents.
+///
+/// # Type Parameters
+/// * `N` - The number of dimensions of the working color space.
+#[derive(Debug)]
+pub struct Quantizer<const N: usize> {
+    swatches: Vec<Point<N>>,
+    dither: Dither,
+    metric: DistanceMetric,
+}
+
+impl<const N: usize> Quantizer<N> {
+    /// Creates a new `Quantizer` over the given palette swatches.
+    ///
+    /// # Arguments
+    /// * `swatches` - The palette swatches, in the working color space, that pixels are mapped onto.
+    /// * `dither` - The dithering mode used to handle quantization error.
+    /// * `metric` - The distance metric used to find the nearest swatch.
+    ///
+    /// # Returns
+    /// A new `Quantizer` instance.
+    ///
+    /// # Errors
+    /// Returns an error if `swatches` is empty.
+    pub fn new(swatches: Vec<Point<N>>, dither: Dither, metric: DistanceMetric) -> Result<Self, &'static str> {
+        if swatches.is_empty() {
+            return Err("The palette must contain at least one swatch.");
+        }
+        Ok(Self {
+            swatches,
+            dither,
+            metric,
+        })
+    }
+
+    /// Quantizes the given source image onto this quantizer's palette.
+    ///
+    /// # Arguments
+    /// * `width` - The width of the source image, in pixels.
+    /// * `pixels` - The source pixels, in row-major order, expressed in the working color space.
+    /// * `to_rgba` - Converts a working-space swatch into its displayable RGBA color.
+    ///
+    /// # Returns
+    /// The palette index of each pixel, and the reconstructed RGBA image built by converting the
+    /// corresponding swatch of each pixel with `to_rgba`.
+    ///
+    /// # Panics
+    /// Panics if `width` is zero or `pixels.len()` is not a multiple of `width`.
+    pub fn quantize(
+        &self,
+        width: usize,
+        pixels: &[Point<N>],
+        to_rgba: impl Fn(&Point<N>) -> Rgba,
+    ) -> (Vec<usize>, Vec<Rgba>) {
+        assert!(width > 0, "width must be greater than zero");
+        assert_eq!(pixels.len() % width, 0, "pixels must form complete rows of `width`");
+
+        let tree = VpTree::build(&self.swatches, self.metric);
+        let (indices, swatches) = match self.dither {
+            Dither::None => self.quantize_flat(&tree, pixels),
+            Dither::FloydSteinberg => self.quantize_with_dither(&tree, width, pixels),
+        };
+        let image = swatches.iter().map(to_rgba).collect();
+        (indices, image)
+    }
+
+    fn quantize_flat(&self, tree: &VpTree<Point<N>>, pixels: &[Point<N>]) -> (Vec<usize>, Vec<Point<N>>) {
+        let mut indices = Vec::with_capacity(pixels.len());
+        let mut reconstructed = Vec::with_capacity(pixels.len());
+        for pixel in pixels {
+            let (index, _) = tree.nearest(pixel).expect("palette must not be empty");
+            indices.push(index);
+            reconstructed.push(self.swatches[index]);
+        }
+        (indices, reconstructed)
+    }
+
+    fn quantize_with_dither(
+        &self,
+        tree: &VpTree<Point<N>>,
+        width: usize,
+        pixels: &[Point<N>],
+    ) -> (Vec<usize>, Vec<Point<N>>) {
+        let height = pixels.len() / width;
+
+        // Accumulates diffused error on top of the original pixels; scanned left-to-right,
+        // top-to-bottom so every neighbor an error is pushed to has not been processed yet.
+        let mut buffer: Vec<[f32; N]> = pixels.iter().map(|pixel| pixel.0).collect();
+        let mut indices = vec![0usize; pixels.len()];
+        let mut reconstructed = vec![self.swatches[0]; pixels.len()];
+
+        for y in 0..height {
+            for x in 0..width {
+                let offset = y * width + x;
+                let working = Point(buffer[offset]);
+                let (index, _) = tree.nearest(&working).expect("palette must not be empty");
+                let swatch = self.swatches[index];
+                indices[offset] = index;
+                reconstructed[offset] = swatch;
+
+                let mut error = [0.0_f32; N];
+                for (dim, value) in error.iter_mut().enumerate() {
+                    *value = working.0[dim] - swatch.0[dim];
+                }
+
+                // Floyd-Steinberg weights for the quantization error of pixel (x, y):
+                //       *    7/16
+                //  3/16 5/16 1/16
+                let mut diffuse = |dx: isize, dy: isize, weight: f32| {
+                    let (nx, ny) = (x as isize + dx, y as isize + dy);
+                    if nx < 0 || ny < 0 || nx as usize >= width || ny as usize >= height {
+                        return;
+                    }
+                    let neighbor = ny as usize * width + nx as usize;
+                    for (dim, value) in buffer[neighbor].iter_mut().enumerate() {
+                        *value += error[dim] * weight;
+                    }
+                };
+                diffuse(1, 0, 7.0 / 16.0);
+                diffuse(-1, 1, 3.0 / 16.0);
+                diffuse(0, 1, 5.0 / 16.0);
+                diffuse(1, 1, 1.0 / 16.0);
+            }
+        }
+        (indices, reconstructed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_rgba(point: &Point<2>) -> Rgba {
+        Rgba {
+            r: point.0[0].clamp(0.0, 255.0) as u8,
+            g: point.0[1].clamp(0.0, 255.0) as u8,
+            b: 0,
+            a: 255,
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_empty_palette() {
+        // Act
+        let result: Result<Quantizer<2>, _> = Quantizer::new(Vec::new(), Dither::None, DistanceMetric::Euclidean);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_quantize_flat_maps_to_nearest_swatch() {
+        // Arrange
+        let swatches = vec![Point([0.0, 0.0]), Point([10.0, 10.0])];
+        let quantizer = Quantizer::new(swatches, Dither::None, DistanceMetric::Euclidean).unwrap();
+        let pixels = vec![Point([0.2, -0.1]), Point([9.8, 10.3])];
+
+        // Act
+        let (indices, image) = quantizer.quantize(2, &pixels, to_rgba);
+
+        // Assert
+        assert_eq!(indices, vec![0, 1]);
+        assert_eq!(image, vec![to_rgba(&Point([0.0, 0.0])), to_rgba(&Point([10.0, 10.0]))]);
+    }
+
+    #[test]
+    fn test_quantize_with_dither_preserves_shape() {
+        // Arrange
+        let swatches = vec![Point([0.0, 0.0]), Point([1.0, 1.0])];
+        let quantizer = Quantizer::new(swatches, Dither::FloydSteinberg, DistanceMetric::Euclidean).unwrap();
+        let pixels = vec![
+            Point([0.1, 0.1]),
+            Point([0.4, 0.4]),
+            Point([0.6, 0.6]),
+            Point([0.9, 0.9]),
+        ];
+
+        // Act
+        let (indices, image) = quantizer.quantize(2, &pixels, to_rgba);
+
+        // Assert
+        assert_eq!(indices.len(), pixels.len());
+        assert_eq!(image.len(), pixels.len());
+        assert!(indices.iter().all(|&index| index < 2));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::math::clustering::cluster::Cluster;
+use crate::math::hilbert::sort_by_hilbert_curve;
+use crate::math::point::Point;
+
+/// ColorSpace selects which cube, and therefore which per-channel value range, a [`Swatch`] is
+/// quantized into before computing its position along the Hilbert ordering curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Quantized sRGB, with each channel in `[0.0, 1.0]`.
+    Rgb,
+    /// Quantized CIE Lab, with `L*` in `[0.0, 100.0]` and `a*`/`b*` in `[-128.0, 127.0]`.
+    Lab,
+}
+
+impl ColorSpace {
+    /// Returns the `(low, high)` bound of each channel in this color space.
+    fn bounds(self) -> ([f32; 3], [f32; 3]) {
+        match self {
+            ColorSpace::Rgb => ([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+            ColorSpace::Lab => ([0.0, -128.0, -128.0], [100.0, 127.0, 127.0]),
+        }
+    }
+
+    /// Quantizes a color in this color space into `bits`-per-channel integer coordinates.
+    fn quantize(self, color: &Point<3>, bits: u32) -> [u32; 3] {
+        let (low, high) = self.bounds();
+        let levels = ((1u32 << bits) - 1) as f32;
+
+        let mut coords = [0u32; 3];
+        for (dim, coord) in coords.iter_mut().enumerate() {
+            let normalized = ((color.0[dim] - low[dim]) / (high[dim] - low[dim])).clamp(0.0, 1.0);
+            *coord = (normalized * levels).round() as u32;
+        }
+        coords
+    }
+}
+
+/// Swatch is a single entry of an extracted palette: its representative color and how many input
+/// points were assigned to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Swatch {
+    /// The swatch's representative color.
+    pub color: Point<3>,
+    /// The number of input points this swatch's cluster contains.
+    pub population: usize,
+}
+
+impl Swatch {
+    /// Builds a palette's swatches from its clusters.
+    ///
+    /// # Arguments
+    /// * `clusters` - The clusters produced by a clustering backend.
+    ///
+    /// # Returns
+    /// One swatch per cluster.
+    #[must_use]
+    pub fn from_clusters(clusters: &[Cluster<Point<3>>]) -> Vec<Self> {
+        clusters
+            .iter()
+            .map(|cluster| Swatch {
+                color: cluster.centroid(),
+                population: cluster.len(),
+            })
+            .collect()
+    }
+
+    /// Sorts swatches in place by ascending position along a Hilbert curve through `color_space`,
+    /// so adjacent swatches in the ordering are perceptually close in color.
+    ///
+    /// # Arguments
+    /// * `swatches` - The swatches to sort.
+    /// * `bits` - The bit depth used to quantize each channel; higher values trade more precision
+    ///   for a larger curve distance.
+    /// * `color_space` - The color space, and its value range, `color` is quantized into.
+    pub fn sort_by_hilbert_order(swatches: &mut [Swatch], bits: u32, color_space: ColorSpace) {
+        sort_by_hilbert_curve(swatches, bits, |swatch| color_space.quantize(&swatch.color, bits));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_clusters() {
+        // Arrange
+        let mut cluster = Cluster::new();
+        cluster.add_point(0, &Point([1.0, 2.0, 3.0]));
+        cluster.add_point(1, &Point([3.0, 2.0, 3.0]));
+
+        // Act
+        let swatches = Swatch::from_clusters(&[cluster]);
+
+        // Assert
+        assert_eq!(swatches.len(), 1);
+        assert_eq!(swatches[0].color, Point([2.0, 2.0, 3.0]));
+        assert_eq!(swatches[0].population, 2);
+    }
+
+    #[test]
+    fn test_sort_by_hilbert_order_rgb() {
+        // Arrange
+        let mut swatches = vec![
+            Swatch {
+                color: Point([1.0, 0.0, 0.0]),
+                population: 1,
+            },
+            Swatch {
+                color: Point([0.0, 0.0, 0.0]),
+                population: 1,
+            },
+            Swatch {
+                color: Point([1.0, 1.0, 0.0]),
+                population: 1,
+            },
+            Swatch {
+                color: Point([0.0, 0.0, 1.0]),
+                population: 1,
+            },
+        ];
+
+        // Act
+        Swatch::sort_by_hilbert_order(&mut swatches, 1, ColorSpace::Rgb);
+
+        // Assert
+        let colors: Vec<Point<3>> = swatches.iter().map(|swatch| swatch.color).collect();
+        assert_eq!(
+            colors,
+            vec![
+                Point([0.0, 0.0, 0.0]),
+                Point([0.0, 0.0, 1.0]),
+                Point([1.0, 1.0, 0.0]),
+                Point([1.0, 0.0, 0.0]),
+            ]
+        );
+    }
+}